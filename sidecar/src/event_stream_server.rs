@@ -0,0 +1,282 @@
+//! The sidecar's own outbound SSE endpoint: re-broadcasts `SseData` received
+//! from upstream node(s) to any number of downstream subscribers.
+//!
+//! Subscribers can narrow what they receive with query parameters on the
+//! event-stream endpoint:
+//!   - `?types=BlockAdded,FinalitySignature` - only the named `SseData` variants
+//!   - `?deploy_hash=<hex>` - only events referencing that deploy
+//!   - `?block_hash=<hex>` - only events referencing that block
+//!
+//! so a lightweight consumer (e.g. a wallet watching one account's deploys)
+//! never has to decode events it doesn't care about.
+
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{Context, Error};
+use casper_event_types::SseData;
+use casper_types::ProtocolVersion;
+use hex_fmt::HexFmt;
+use tokio::sync::{broadcast, oneshot};
+use warp::{sse::Event, Filter};
+
+const DEFAULT_BUFFER_LENGTH: usize = 5000;
+const DEFAULT_MAX_CONCURRENT_SUBSCRIBERS: usize = 100;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    port: u16,
+    event_stream_buffer_length: usize,
+    max_concurrent_subscribers: usize,
+}
+
+impl Config {
+    pub fn new(
+        port: u16,
+        event_stream_buffer_length: Option<usize>,
+        max_concurrent_subscribers: Option<usize>,
+    ) -> Self {
+        Config {
+            port,
+            event_stream_buffer_length: event_stream_buffer_length.unwrap_or(DEFAULT_BUFFER_LENGTH),
+            max_concurrent_subscribers: max_concurrent_subscribers
+                .unwrap_or(DEFAULT_MAX_CONCURRENT_SUBSCRIBERS),
+        }
+    }
+}
+
+/// A subscriber-supplied predicate evaluated against every `SseData` before
+/// it is written to that subscriber's channel.
+#[derive(Clone, Debug, Default)]
+struct SubscriberFilter {
+    types: Option<HashSet<String>>,
+    deploy_hash: Option<String>,
+    block_hash: Option<String>,
+}
+
+impl SubscriberFilter {
+    fn from_query(query: &std::collections::HashMap<String, String>) -> Self {
+        let types = query
+            .get("types")
+            .map(|value| value.split(',').map(str::to_string).collect());
+        let deploy_hash = query.get("deploy_hash").map(|value| value.to_lowercase());
+        let block_hash = query.get("block_hash").map(|value| value.to_lowercase());
+
+        SubscriberFilter {
+            types,
+            deploy_hash,
+            block_hash,
+        }
+    }
+
+    fn matches(&self, sse_data: &SseData) -> bool {
+        if let Some(types) = &self.types {
+            if !types.contains(crate::event_type_name(sse_data)) {
+                return false;
+            }
+        }
+
+        if let Some(deploy_hash) = &self.deploy_hash {
+            if extract_deploy_hash(sse_data).as_deref() != Some(deploy_hash.as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(block_hash) = &self.block_hash {
+            if extract_block_hash(sse_data).as_deref() != Some(block_hash.as_str()) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn extract_deploy_hash(sse_data: &SseData) -> Option<String> {
+    match sse_data {
+        SseData::DeployAccepted { deploy } => Some(HexFmt(deploy.id().inner()).to_string()),
+        SseData::DeployProcessed { deploy_hash, .. } | SseData::DeployExpired { deploy_hash } => {
+            Some(HexFmt(deploy_hash.inner()).to_string())
+        }
+        _ => None,
+    }
+}
+
+fn extract_block_hash(sse_data: &SseData) -> Option<String> {
+    match sse_data {
+        SseData::BlockAdded { block_hash, .. } => Some(HexFmt(block_hash.inner()).to_string()),
+        SseData::DeployProcessed { block_hash, .. } => Some(HexFmt(block_hash.inner()).to_string()),
+        SseData::FinalitySignature(fs) => Some(HexFmt(fs.block_hash.inner()).to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(pairs: &[(&str, &str)]) -> std::collections::HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        let filter = SubscriberFilter::from_query(&query(&[]));
+        assert!(filter.matches(&SseData::Shutdown));
+    }
+
+    #[test]
+    fn types_filter_accepts_only_the_listed_comma_separated_variants() {
+        let filter = SubscriberFilter::from_query(&query(&[("types", "Shutdown,BlockAdded")]));
+        assert!(filter.matches(&SseData::Shutdown));
+
+        let filter = SubscriberFilter::from_query(&query(&[("types", "BlockAdded,Step")]));
+        assert!(!filter.matches(&SseData::Shutdown));
+    }
+
+    #[test]
+    fn deploy_hash_filter_rejects_events_with_no_deploy_hash() {
+        let filter = SubscriberFilter::from_query(&query(&[("deploy_hash", "aabbcc")]));
+        assert!(!filter.matches(&SseData::Shutdown));
+    }
+
+    #[test]
+    fn block_hash_filter_rejects_events_with_no_block_hash() {
+        let filter = SubscriberFilter::from_query(&query(&[("block_hash", "aabbcc")]));
+        assert!(!filter.matches(&SseData::Shutdown));
+    }
+
+    #[test]
+    fn deploy_and_block_hash_filters_are_lowercased() {
+        let filter = SubscriberFilter::from_query(&query(&[("deploy_hash", "AABBCC")]));
+        assert_eq!(filter.deploy_hash.as_deref(), Some("aabbcc"));
+
+        let filter = SubscriberFilter::from_query(&query(&[("block_hash", "AABBCC")]));
+        assert_eq!(filter.block_hash.as_deref(), Some("aabbcc"));
+    }
+}
+
+/// Releases this subscriber's slot in `max_concurrent_subscribers` once its
+/// stream is dropped, whatever the reason - a clean disconnect or the client
+/// going away. A request rejected at the cap takes no slot and gets no guard.
+struct SubscriberGuard {
+    active_subscribers: Arc<Mutex<usize>>,
+}
+
+impl Drop for SubscriberGuard {
+    fn drop(&mut self) {
+        let mut count = self.active_subscribers.lock().unwrap();
+        *count = count.saturating_sub(1);
+    }
+}
+
+/// Re-broadcasts `SseData` received via [`EventStreamServer::broadcast`] to
+/// every connected subscriber of the `GET /events` endpoint, applying each
+/// subscriber's own [`SubscriberFilter`] before writing to their stream.
+pub struct EventStreamServer {
+    sender: broadcast::Sender<SseData>,
+    shutdown_tx: Option<oneshot::Sender<()>>,
+}
+
+impl EventStreamServer {
+    /// `listener` must already be bound to `config.port` - see `bind_port`
+    /// in `main.rs`, which reserves it during startup so a port conflict is
+    /// reported before any task is spawned rather than once the API version
+    /// of every upstream node has been confirmed.
+    pub fn new(
+        config: Config,
+        _storage_path: PathBuf,
+        _api_version: ProtocolVersion,
+        listener: std::net::TcpListener,
+    ) -> Result<Self, Error> {
+        tracing::info!(port = config.port, "Starting EventStreamServer");
+        let (sender, _) = broadcast::channel(config.event_stream_buffer_length);
+        let max_concurrent_subscribers = config.max_concurrent_subscribers;
+        let active_subscribers = Arc::new(Mutex::new(0usize));
+
+        let broadcast_sender = sender.clone();
+        let route = warp::path("events")
+            .and(warp::get())
+            .and(warp::query::<std::collections::HashMap<String, String>>())
+            .map(move |query: std::collections::HashMap<String, String>| {
+                let filter = SubscriberFilter::from_query(&query);
+
+                // Reserve a slot up front so two requests racing for the
+                // last one can't both be admitted; `_guard` releases it
+                // again once this subscriber's stream is dropped, whether
+                // that's a clean disconnect or the client going away.
+                let guard = {
+                    let mut count = active_subscribers.lock().unwrap();
+                    if *count >= max_concurrent_subscribers {
+                        None
+                    } else {
+                        *count += 1;
+                        Some(SubscriberGuard {
+                            active_subscribers: active_subscribers.clone(),
+                        })
+                    }
+                };
+
+                let broadcast_sender = broadcast_sender.clone();
+                let stream = async_stream::stream! {
+                    let Some(_guard) = guard else {
+                        // Subscriber cap reached; don't subscribe at all -
+                        // the stream ends immediately instead of serving a
+                        // client we have no room for.
+                        return;
+                    };
+
+                    let mut receiver = broadcast_sender.subscribe();
+                    loop {
+                        match receiver.recv().await {
+                            Ok(sse_data) => {
+                                if filter.matches(&sse_data) {
+                                    yield Ok::<_, std::convert::Infallible>(
+                                        Event::default().json_data(&sse_data).unwrap_or_else(|_| Event::default()),
+                                    );
+                                }
+                            }
+                            Err(broadcast::error::RecvError::Closed) => break,
+                            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        }
+                    }
+                };
+
+                warp::sse::reply(warp::sse::keep_alive().stream(stream))
+            });
+
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let listener = tokio::net::TcpListener::from_std(listener)
+            .context("Error handing bound listener to the event stream server")?;
+        let incoming = tokio_stream::wrappers::TcpListenerStream::new(listener);
+        let server = warp::serve(route).serve_incoming_with_graceful_shutdown(incoming, async {
+            let _ = shutdown_rx.await;
+        });
+        tokio::spawn(server);
+
+        Ok(EventStreamServer {
+            sender,
+            shutdown_tx: Some(shutdown_tx),
+        })
+    }
+
+    /// Pushes a single event out to every connected subscriber whose filter
+    /// accepts it. Subscribers with no live receivers simply drop the send.
+    pub fn broadcast(&mut self, sse_data: SseData) {
+        let _ = self.sender.send(sse_data);
+    }
+
+    /// Signals the warp server to stop accepting new subscribers and close
+    /// existing connections once their in-flight writes complete.
+    pub async fn shutdown(&mut self) {
+        if let Some(shutdown_tx) = self.shutdown_tx.take() {
+            let _ = shutdown_tx.send(());
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Offline bulk loader: replays newline-delimited JSON SSE event records
+//! (captured from a live node, or exported from another backend) through the
+//! same `handle_single_event` pipeline `run` uses, so the resulting database
+//! is indistinguishable from one built by watching a live node.
+//!
+//! Each input line is a JSON object `{"id": <u32|null>, "source": "<addr>",
+//! "data": <SseData JSON>}`.
+
+use std::{io::BufRead, path::PathBuf};
+
+use anyhow::{Context, Error};
+use serde::Deserialize;
+use tokio::sync::mpsc::{channel as mpsc_channel, Sender};
+use tracing::{info, warn};
+
+use casper_event_listener::SseEvent;
+use casper_event_types::SseData;
+use casper_types::ProtocolVersion;
+
+use crate::{
+    event_stream_server::{Config as SseConfig, EventStreamServer},
+    handle_single_event,
+    metrics::Metrics,
+    types::config::Config,
+    Database,
+};
+
+const IMPORT_CHANNEL_SIZE: usize = 1000;
+
+#[derive(Deserialize)]
+struct RawSseEventRecord {
+    id: Option<u32>,
+    source: String,
+    data: SseData,
+}
+
+/// Reads newline-delimited `RawSseEventRecord`s from `reader` and feeds them
+/// through `handle_single_event`, reporting how many were newly inserted vs.
+/// rejected as duplicates once the input is exhausted.
+///
+/// When `rebroadcast_listener` is `Some` (i.e. `--rebroadcast` was passed), a
+/// real `EventStreamServer` is spun up on it and fed every event as it's
+/// replayed, so a subscriber connected to `config.event_stream_server.port`
+/// during the import sees the same stream a live node would have produced.
+pub async fn run_import(
+    reader: impl BufRead,
+    database: Database,
+    config: &Config,
+    rebroadcast_listener: Option<std::net::TcpListener>,
+) -> Result<(), Error> {
+    let (inbound_sender, mut inbound_receiver) = mpsc_channel(IMPORT_CHANNEL_SIZE);
+    let (outbound_sse_data_sender, mut outbound_sse_data_receiver) =
+        mpsc_channel::<SseData>(IMPORT_CHANNEL_SIZE);
+
+    let mut event_stream_server = match rebroadcast_listener {
+        Some(listener) => Some(
+            EventStreamServer::new(
+                SseConfig::new(
+                    config.event_stream_server.port,
+                    Some(config.event_stream_server.event_stream_buffer_length),
+                    Some(config.event_stream_server.max_concurrent_subscribers),
+                ),
+                PathBuf::from(&config.storage.storage_path),
+                // An import replays already-validated events, so there's no
+                // live node to report an API version; EventStreamServer
+                // doesn't use this beyond accepting it.
+                ProtocolVersion::from_parts(1, 0, 0),
+                listener,
+            )
+            .context("Error starting EventStreamServer for --rebroadcast")?,
+        ),
+        None => None,
+    };
+
+    // Offline imports have no subscribers of their own; drain the outbound
+    // channel so `handle_single_event`'s sends never block, forwarding to
+    // `event_stream_server` only when the caller asked to re-broadcast.
+    let drain_handle = tokio::spawn(async move {
+        let mut forwarded = 0u64;
+        while let Some(sse_data) = outbound_sse_data_receiver.recv().await {
+            if let Some(event_stream_server) = event_stream_server.as_mut() {
+                event_stream_server.broadcast(sse_data);
+                forwarded += 1;
+            }
+        }
+        if let Some(mut event_stream_server) = event_stream_server {
+            event_stream_server.shutdown().await;
+        }
+        forwarded
+    });
+
+    let metrics = Metrics::new();
+    // An import always stores every event type regardless of the live
+    // `event_stream_server.disabled_event_types` config, since its purpose
+    // is to faithfully reproduce a captured data set.
+    let disabled_event_types = std::sync::Arc::new(std::collections::HashSet::new());
+    let worker_database = database.clone();
+    let worker_metrics = metrics.clone();
+    let worker_handle = tokio::spawn(async move {
+        while let Some(sse_event) = inbound_receiver.recv().await {
+            handle_single_event(
+                sse_event,
+                worker_database.clone(),
+                false,
+                outbound_sse_data_sender.clone(),
+                worker_metrics.clone(),
+                disabled_event_types.clone(),
+            )
+            .await;
+        }
+    });
+
+    let mut total = 0u64;
+    let mut malformed = 0u64;
+    for line in reader.lines() {
+        let line = line.context("Error reading line from import source")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match parse_record(&line) {
+            Ok(sse_event) => {
+                total += 1;
+                send_or_log(&inbound_sender, sse_event).await;
+            }
+            Err(err) => {
+                malformed += 1;
+                warn!(%err, "Skipping malformed import record");
+            }
+        }
+    }
+
+    drop(inbound_sender);
+    worker_handle
+        .await
+        .context("Import worker task panicked")?;
+    let forwarded = drain_handle.await.context("Import drain task panicked")?;
+
+    info!(
+        total,
+        malformed,
+        saved = metrics.saved_total(),
+        duplicates = metrics.duplicate_total(),
+        errors = metrics.error_total(),
+        forwarded,
+        "Import finished"
+    );
+
+    Ok(())
+}
+
+fn parse_record(line: &str) -> Result<SseEvent, Error> {
+    let record: RawSseEventRecord =
+        serde_json::from_str(line).context("Error deserializing SSE event record")?;
+
+    Ok(SseEvent {
+        id: record.id.unwrap_or(0),
+        source: record
+            .source
+            .parse()
+            .context("Error parsing event source address")?,
+        data: record.data,
+    })
+}
+
+async fn send_or_log(sender: &Sender<SseEvent>, sse_event: SseEvent) {
+    if sender.send(sse_event).await.is_err() {
+        warn!("Import worker channel closed early; remaining records will be dropped");
+    }
+}
@@ -1,10 +1,13 @@
 extern crate core;
 
 mod event_stream_server;
+mod import;
 #[cfg(test)]
 mod integration_tests;
+mod metrics;
 #[cfg(test)]
 mod performance_tests;
+mod postgres_database;
 mod rest_server;
 mod sql;
 mod sqlite_database;
@@ -14,43 +17,167 @@ mod types;
 mod utils;
 
 use std::{
+    collections::HashSet,
     net::IpAddr,
     path::{Path, PathBuf},
     str::FromStr,
+    sync::Arc,
     time::Duration,
 };
 
 use anyhow::{Context, Error};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use futures::future::join_all;
 use hex_fmt::HexFmt;
 use tokio::{
+    signal::unix::{signal, SignalKind},
     sync::mpsc::{channel as mpsc_channel, Receiver, Sender},
     task::JoinHandle,
 };
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, trace, warn};
 
 use casper_event_listener::{EventListener, NodeConnectionInterface, SseEvent};
 use casper_event_types::SseData;
 use casper_types::ProtocolVersion;
 
+use async_trait::async_trait;
+
 use crate::{
     event_stream_server::{Config as SseConfig, EventStreamServer},
+    metrics::Metrics,
+    postgres_database::PostgresDatabase,
     rest_server::run_server as start_rest_server,
     sqlite_database::SqliteDatabase,
     types::{
-        config::{read_config, Config},
+        config::{read_config, Config, StorageEngine},
         database::{DatabaseWriteError, DatabaseWriter},
         sse_events::*,
     },
 };
 
+/// Dispatches `DatabaseWriter` calls to whichever backend `storage.engine`
+/// selected, so `sse_processor` and `handle_single_event` take this one type
+/// whatever the backend. The REST server is not given a database at all.
+#[derive(Clone)]
+enum Database {
+    Sqlite(SqliteDatabase),
+    Postgres(PostgresDatabase),
+}
+
+#[async_trait]
+impl DatabaseWriter for Database {
+    async fn save_block_added(
+        &self,
+        block_added: BlockAdded,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        match self {
+            Database::Sqlite(db) => db.save_block_added(block_added, event_id, event_source_address).await,
+            Database::Postgres(db) => db.save_block_added(block_added, event_id, event_source_address).await,
+        }
+    }
+
+    async fn save_deploy_accepted(
+        &self,
+        deploy_accepted: DeployAccepted,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        match self {
+            Database::Sqlite(db) => db.save_deploy_accepted(deploy_accepted, event_id, event_source_address).await,
+            Database::Postgres(db) => db.save_deploy_accepted(deploy_accepted, event_id, event_source_address).await,
+        }
+    }
+
+    async fn save_deploy_processed(
+        &self,
+        deploy_processed: DeployProcessed,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        match self {
+            Database::Sqlite(db) => db.save_deploy_processed(deploy_processed, event_id, event_source_address).await,
+            Database::Postgres(db) => db.save_deploy_processed(deploy_processed, event_id, event_source_address).await,
+        }
+    }
+
+    async fn save_deploy_expired(
+        &self,
+        deploy_expired: DeployExpired,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        match self {
+            Database::Sqlite(db) => db.save_deploy_expired(deploy_expired, event_id, event_source_address).await,
+            Database::Postgres(db) => db.save_deploy_expired(deploy_expired, event_id, event_source_address).await,
+        }
+    }
+
+    async fn save_fault(
+        &self,
+        fault: Fault,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        match self {
+            Database::Sqlite(db) => db.save_fault(fault, event_id, event_source_address).await,
+            Database::Postgres(db) => db.save_fault(fault, event_id, event_source_address).await,
+        }
+    }
+
+    async fn save_finality_signature(
+        &self,
+        finality_signature: FinalitySignature,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        match self {
+            Database::Sqlite(db) => db.save_finality_signature(finality_signature, event_id, event_source_address).await,
+            Database::Postgres(db) => db.save_finality_signature(finality_signature, event_id, event_source_address).await,
+        }
+    }
+
+    async fn save_step(
+        &self,
+        step: Step,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        match self {
+            Database::Sqlite(db) => db.save_step(step, event_id, event_source_address).await,
+            Database::Postgres(db) => db.save_step(step, event_id, event_source_address).await,
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct CmdLineArgs {
     /// Path to the TOML-formatted config file
-    #[arg(short, long, value_name = "FILE")]
-    path_to_config: String,
+    #[arg(short, long, value_name = "FILE", required_unless_present = "command")]
+    path_to_config: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Replay archived SSE events from a JSONL file (or STDIN) into the configured store,
+    /// bypassing the live node connections.
+    Import {
+        /// Path to the TOML-formatted config file describing the storage backend to import into
+        #[arg(short, long, value_name = "FILE")]
+        path_to_config: String,
+        /// Path to a newline-delimited JSON file of SSE event records; reads STDIN if omitted
+        #[arg(long, value_name = "FILE")]
+        input: Option<String>,
+        /// Re-broadcast imported events on the outbound event stream as they're replayed
+        #[arg(long)]
+        rebroadcast: bool,
+    },
 }
 
 const DEFAULT_CHANNEL_SIZE: usize = 1000;
@@ -62,29 +189,147 @@ async fn main() -> Result<(), Error> {
 
     let args = CmdLineArgs::parse();
 
-    let path_to_config = args.path_to_config;
+    match args.command {
+        Some(Command::Import {
+            path_to_config,
+            input,
+            rebroadcast,
+        }) => run_import_command(path_to_config, input, rebroadcast).await,
+        None => {
+            let path_to_config = args
+                .path_to_config
+                .expect("clap enforces --path-to-config when no subcommand is given");
+
+            let config: Config =
+                read_config(&path_to_config).context("Error constructing config")?;
+            info!("Configuration loaded");
+
+            run(config).await
+        }
+    }
+}
 
+async fn run_import_command(
+    path_to_config: String,
+    input: Option<String>,
+    rebroadcast: bool,
+) -> Result<(), Error> {
     let config: Config = read_config(&path_to_config).context("Error constructing config")?;
-    info!("Configuration loaded");
 
-    run(config).await
+    let path_to_database_dir = Path::new(&config.storage.storage_path);
+    let database = match config.storage.engine {
+        StorageEngine::Sqlite => Database::Sqlite(
+            SqliteDatabase::new(path_to_database_dir, config.storage.sqlite_config.clone())
+                .await
+                .context("Error instantiating Sqlite database")?,
+        ),
+        StorageEngine::Postgres => Database::Postgres(
+            PostgresDatabase::new(config.storage.postgres_config.clone())
+                .await
+                .context("Error instantiating Postgres database")?,
+        ),
+    };
+
+    let rebroadcast_listener = if rebroadcast {
+        Some(
+            bind_port(config.event_stream_server.port).with_context(|| {
+                format!(
+                    "Error binding event stream server port {} for --rebroadcast",
+                    config.event_stream_server.port
+                )
+            })?,
+        )
+    } else {
+        None
+    };
+
+    match input {
+        Some(path) => {
+            let file = std::fs::File::open(&path)
+                .with_context(|| format!("Error opening import file {path}"))?;
+            import::run_import(
+                std::io::BufReader::new(file),
+                database,
+                &config,
+                rebroadcast_listener,
+            )
+            .await
+        }
+        None => {
+            let stdin = std::io::stdin();
+            import::run_import(stdin.lock(), database, &config, rebroadcast_listener).await
+        }
+    }
+}
+
+/// Parses every configured connection's `ip_address`, aggregating all
+/// failures into a single error instead of bailing out on the first bad
+/// entry, so a typo'd config reports every offending node in one pass.
+fn resolve_connection_ips(config: &Config) -> Result<Vec<IpAddr>, Error> {
+    let mut ips = Vec::with_capacity(config.connections.len());
+    let mut errors = Vec::new();
+
+    for connection in &config.connections {
+        match IpAddr::from_str(&connection.ip_address) {
+            Ok(ip_address) => ips.push(ip_address),
+            Err(err) => errors.push(format!("{}: {err}", connection.ip_address)),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::msg(format!(
+            "Invalid connection ip_address(es): {}",
+            errors.join(", ")
+        )));
+    }
+
+    Ok(ips)
+}
+
+/// Reserves `port` on all interfaces up front, returning the bound listener
+/// for the caller to hand off to the task that will actually serve on it.
+fn bind_port(port: u16) -> Result<std::net::TcpListener, Error> {
+    let listener = std::net::TcpListener::bind(("0.0.0.0", port))?;
+    listener.set_nonblocking(true)?;
+    Ok(listener)
 }
 
 async fn run(config: Config) -> Result<(), Error> {
+    let shutdown_token = CancellationToken::new();
+    tokio::spawn(listen_for_shutdown_signals(shutdown_token.clone()));
+
+    // Resolve every upstream connection address and reserve both of the
+    // sidecar's own listening ports up front, so a bad config or a port
+    // conflict fails loudly before any task is spawned rather than
+    // surfacing deep inside a background task later on.
+    let connection_ips = resolve_connection_ips(&config)?;
+    let rest_server_listener = bind_port(config.rest_server.port)
+        .with_context(|| format!("Error binding REST server port {}", config.rest_server.port))?;
+    let event_stream_server_listener =
+        bind_port(config.event_stream_server.port).with_context(|| {
+            format!(
+                "Error binding event stream server port {}",
+                config.event_stream_server.port
+            )
+        })?;
+
+    let shutdown_token = CancellationToken::new();
+    tokio::spawn(listen_for_shutdown_signals(shutdown_token.clone()));
+
     let mut event_listeners = Vec::with_capacity(config.connections.len());
 
     let mut sse_data_receivers = Vec::new();
     let (api_version_tx, mut api_version_rx) =
         mpsc_channel::<Result<ProtocolVersion, Error>>(config.connections.len() + 10);
 
-    for connection in &config.connections {
+    for (connection, ip_address) in config.connections.iter().zip(connection_ips) {
         let (inbound_sse_data_sender, inbound_sse_data_receiver) =
             mpsc_channel(config.inbound_channel_size.unwrap_or(DEFAULT_CHANNEL_SIZE));
 
         sse_data_receivers.push(inbound_sse_data_receiver);
 
         let node_interface = NodeConnectionInterface {
-            ip_address: IpAddr::from_str(&connection.ip_address)?,
+            ip_address,
             sse_port: connection.sse_port,
             rest_port: connection.rest_port,
         };
@@ -102,16 +347,31 @@ async fn run(config: Config) -> Result<(), Error> {
 
     let path_to_database_dir = Path::new(&config.storage.storage_path);
 
-    // Creates and initialises Sqlite database
-    let sqlite_database =
-        SqliteDatabase::new(path_to_database_dir, config.storage.sqlite_config.clone())
-            .await
-            .context("Error instantiating database")?;
+    // Creates and initialises the configured storage backend.
+    let database = match config.storage.engine {
+        StorageEngine::Sqlite => Database::Sqlite(
+            SqliteDatabase::new(path_to_database_dir, config.storage.sqlite_config.clone())
+                .await
+                .context("Error instantiating Sqlite database")?,
+        ),
+        StorageEngine::Postgres => Database::Postgres(
+            PostgresDatabase::new(config.storage.postgres_config.clone())
+                .await
+                .context("Error instantiating Postgres database")?,
+        ),
+    };
 
     // Prepare the REST server task - this will be executed later
     let rest_server_handle = tokio::spawn(start_rest_server(
         config.rest_server.clone(),
-        sqlite_database.clone(),
+        shutdown_token.clone(),
+        rest_server_listener,
+    ));
+
+    let metrics = Metrics::new();
+    let metrics_server_handle = tokio::spawn(metrics::run_server(
+        config.metrics_server.port,
+        metrics.clone(),
     ));
 
     // This channel allows SseData to be sent from multiple connected nodes to the single EventStreamServer.
@@ -120,7 +380,17 @@ async fn run(config: Config) -> Result<(), Error> {
 
     let connection_configs = config.connections.clone();
 
+    let disabled_event_types: Arc<HashSet<String>> = Arc::new(
+        config
+            .event_stream_server
+            .disabled_event_types
+            .iter()
+            .cloned()
+            .collect(),
+    );
+
     // Task to manage incoming events from all three filters
+    let listening_task_shutdown_token = shutdown_token.clone();
     let listening_task_handle = tokio::spawn(async move {
         let mut join_handles = Vec::with_capacity(event_listeners.len());
 
@@ -134,8 +404,11 @@ async fn run(config: Config) -> Result<(), Error> {
                 api_version_tx.clone(),
                 sse_data_receiver,
                 outbound_sse_data_sender.clone(),
-                sqlite_database.clone(),
+                database.clone(),
                 connection_config.enable_logging,
+                metrics.clone(),
+                listening_task_shutdown_token.clone(),
+                disabled_event_types.clone(),
             ));
 
             join_handles.push(join_handle);
@@ -147,18 +420,33 @@ async fn run(config: Config) -> Result<(), Error> {
 
         let _ = join_all(join_handles).await;
 
-        Err::<(), Error>(Error::msg("Connected node(s) are unavailable"))
+        if listening_task_shutdown_token.is_cancelled() {
+            Ok(())
+        } else {
+            Err(Error::msg("Connected node(s) are unavailable"))
+        }
     });
 
+    let event_broadcasting_shutdown_token = shutdown_token.clone();
     let event_broadcasting_handle = tokio::spawn(async move {
         // Wait for the listeners to report the API version before spinning up the Event Stream Server.
+        // This also has to race the shutdown token: if every upstream node is
+        // unreachable (or never finishes connecting), this loop would
+        // otherwise never observe a SIGTERM/SIGINT and `try_join!` would hang.
         let mut api_versions = Vec::new();
-        while let Some(api_fetch_res) = api_version_rx.recv().await {
-            match api_fetch_res {
-                Ok(version) => api_versions.push(version),
-                Err(err) => {
-                    error!("Error fetching API version from connected node(s): {err}");
-                    return Err(err);
+        loop {
+            tokio::select! {
+                biased;
+                _ = event_broadcasting_shutdown_token.cancelled() => return Ok(()),
+                maybe_api_fetch_res = api_version_rx.recv() => {
+                    match maybe_api_fetch_res {
+                        Some(Ok(version)) => api_versions.push(version),
+                        Some(Err(err)) => {
+                            error!("Error fetching API version from connected node(s): {err}");
+                            return Err(err);
+                        }
+                        None => break,
+                    }
                 }
             }
         }
@@ -173,7 +461,8 @@ async fn run(config: Config) -> Result<(), Error> {
             ));
         }
 
-        // Create new instance for the Sidecar's Event Stream Server
+        // Create new instance for the Sidecar's Event Stream Server, reusing
+        // the listener reserved at startup rather than binding afresh here.
         let mut event_stream_server = EventStreamServer::new(
             SseConfig::new(
                 config.event_stream_server.port,
@@ -182,15 +471,39 @@ async fn run(config: Config) -> Result<(), Error> {
             ),
             PathBuf::from(&config.storage.storage_path),
             api_versions[0],
+            event_stream_server_listener,
         )
         .context("Error starting EventStreamServer")?;
 
-        while let Some(sse_data) = outbound_sse_data_receiver.recv().await {
-            event_stream_server.broadcast(sse_data);
+        loop {
+            tokio::select! {
+                biased;
+                _ = event_broadcasting_shutdown_token.cancelled() => {
+                    // Stop accepting new inbound events and drain whatever is
+                    // already buffered so subscribers see a consistent final
+                    // state before the stream is closed.
+                    outbound_sse_data_receiver.close();
+                    while let Ok(sse_data) = outbound_sse_data_receiver.try_recv() {
+                        event_stream_server.broadcast(sse_data);
+                    }
+                    event_stream_server.shutdown().await;
+                    return Ok(());
+                }
+                maybe_sse_data = outbound_sse_data_receiver.recv() => {
+                    match maybe_sse_data {
+                        Some(sse_data) => event_stream_server.broadcast(sse_data),
+                        None => return Err(Error::msg("Event broadcasting finished")),
+                    }
+                }
+            }
         }
-        Err::<(), Error>(Error::msg("Event broadcasting finished"))
     });
 
+    // The metrics server runs for the lifetime of the process; it has no
+    // failure mode that should bring the rest of the sidecar down, so it is
+    // deliberately left out of the `try_join!` below.
+    drop(metrics_server_handle);
+
     tokio::try_join!(
         flatten_handle(event_broadcasting_handle),
         flatten_handle(rest_server_handle),
@@ -199,6 +512,21 @@ async fn run(config: Config) -> Result<(), Error> {
     .map(|_| Ok(()))?
 }
 
+/// Waits for SIGTERM or SIGINT and cancels `shutdown_token`, giving every
+/// task wired to it a chance to drain in-flight work before the process
+/// exits, rather than being dropped mid-write by a supervisor's hard kill.
+async fn listen_for_shutdown_signals(shutdown_token: CancellationToken) {
+    let mut sigterm =
+        signal(SignalKind::terminate()).expect("Error installing SIGTERM handler");
+
+    tokio::select! {
+        _ = sigterm.recv() => info!("Received SIGTERM, shutting down gracefully"),
+        _ = tokio::signal::ctrl_c() => info!("Received SIGINT, shutting down gracefully"),
+    }
+
+    shutdown_token.cancel();
+}
+
 async fn flatten_handle<T>(handle: JoinHandle<Result<T, Error>>) -> Result<T, Error> {
     match handle.await {
         Ok(Ok(result)) => Ok(result),
@@ -207,17 +535,48 @@ async fn flatten_handle<T>(handle: JoinHandle<Result<T, Error>>) -> Result<T, Er
     }
 }
 
+/// Name used both for the `event_stream_server.disabled_event_types` config
+/// list and the `?types=` subscriber filter, so operators and subscribers
+/// speak the same vocabulary.
+pub(crate) fn event_type_name(sse_data: &SseData) -> &'static str {
+    match sse_data {
+        SseData::ApiVersion(_) => "ApiVersion",
+        SseData::BlockAdded { .. } => "BlockAdded",
+        SseData::DeployAccepted { .. } => "DeployAccepted",
+        SseData::DeployProcessed { .. } => "DeployProcessed",
+        SseData::DeployExpired { .. } => "DeployExpired",
+        SseData::Fault { .. } => "Fault",
+        SseData::FinalitySignature(_) => "FinalitySignature",
+        SseData::Step { .. } => "Step",
+        SseData::Shutdown => "Shutdown",
+    }
+}
+
 /// Function to handle single event in the sse_processor.
 /// Returns false if the handling indicated that no other messages should be processed.
 /// Returns true otherwise.
 async fn handle_single_event(
     sse_event: SseEvent,
-    sqlite_database: SqliteDatabase,
+    database: Database,
     enable_event_logging: bool,
     outbound_sse_data_sender: Sender<SseData>,
+    metrics: Metrics,
+    disabled_event_types: Arc<HashSet<String>>,
 ) {
+    let source = sse_event.source.to_string();
+    metrics.record_event_received(&sse_event.data, &source);
+
+    if disabled_event_types.contains(event_type_name(&sse_event.data)) {
+        trace!(
+            event_type = event_type_name(&sse_event.data),
+            "Event type disabled via event_stream_server config, dropping"
+        );
+        return;
+    }
+
     match sse_event.data {
         SseData::ApiVersion(version) => {
+            metrics.node_connected(&source);
             if enable_event_logging {
                 info!(%version, "API Version");
             }
@@ -229,7 +588,7 @@ async fn handle_single_event(
                 debug!("Block Added: {}", hex_block_hash);
             }
 
-            let res = sqlite_database
+            let res = database
                 .save_block_added(
                     BlockAdded::new(block_hash, block.clone()),
                     sse_event.id,
@@ -239,17 +598,22 @@ async fn handle_single_event(
 
             match res {
                 Ok(_) => {
+                    metrics.record_saved(&source);
                     let _ =
                         outbound_sse_data_sender.send(SseData::BlockAdded { block, block_hash }).await;
                 }
                 Err(DatabaseWriteError::UniqueConstraint(uc_err)) => {
+                    metrics.record_duplicate(&source);
                     debug!(
                         "Already received BlockAdded ({}), logged in event_log",
                         HexFmt(block_hash.inner())
                     );
                     trace!(?uc_err);
                 }
-                Err(other_err) => warn!(?other_err, "Unexpected error saving BlockAdded"),
+                Err(other_err) => {
+                    metrics.record_error(&source);
+                    warn!(?other_err, "Unexpected error saving BlockAdded")
+                }
             }
         }
         SseData::DeployAccepted { deploy } => {
@@ -259,22 +623,27 @@ async fn handle_single_event(
                 debug!("Deploy Accepted: {}", hex_deploy_hash);
             }
             let deploy_accepted = DeployAccepted::new(deploy.clone());
-            let res = sqlite_database
+            let res = database
                 .save_deploy_accepted(deploy_accepted, sse_event.id, sse_event.source.to_string())
                 .await;
 
             match res {
                 Ok(_) => {
+                    metrics.record_saved(&source);
                     let _ = outbound_sse_data_sender.send(SseData::DeployAccepted { deploy }).await;
                 }
                 Err(DatabaseWriteError::UniqueConstraint(uc_err)) => {
+                    metrics.record_duplicate(&source);
                     debug!(
                         "Already received DeployAccepted ({}), logged in event_log",
                         HexFmt(deploy.id().inner())
                     );
                     trace!(?uc_err);
                 }
-                Err(other_err) => warn!(?other_err, "Unexpected error saving DeployAccepted"),
+                Err(other_err) => {
+                    metrics.record_error(&source);
+                    warn!(?other_err, "Unexpected error saving DeployAccepted")
+                }
             }
         }
         SseData::DeployExpired { deploy_hash } => {
@@ -283,7 +652,7 @@ async fn handle_single_event(
                 info!("Deploy Expired: {:18}", hex_deploy_hash);
                 debug!("Deploy Expired: {}", hex_deploy_hash);
             }
-            let res = sqlite_database
+            let res = database
                 .save_deploy_expired(
                     DeployExpired::new(deploy_hash),
                     sse_event.id,
@@ -293,16 +662,21 @@ async fn handle_single_event(
 
             match res {
                 Ok(_) => {
+                    metrics.record_saved(&source);
                     let _ = outbound_sse_data_sender.send(SseData::DeployExpired { deploy_hash }).await;
                 }
                 Err(DatabaseWriteError::UniqueConstraint(uc_err)) => {
+                    metrics.record_duplicate(&source);
                     debug!(
                         "Already received DeployExpired ({}), logged in event_log",
                         HexFmt(deploy_hash.inner())
                     );
                     trace!(?uc_err);
                 }
-                Err(other_err) => warn!(?other_err, "Unexpected error saving DeployExpired"),
+                Err(other_err) => {
+                    metrics.record_error(&source);
+                    warn!(?other_err, "Unexpected error saving DeployExpired")
+                }
             }
         }
         SseData::DeployProcessed {
@@ -328,7 +702,7 @@ async fn handle_single_event(
                 block_hash.clone(),
                 execution_result.clone(),
             );
-            let res = sqlite_database
+            let res = database
                 .save_deploy_processed(
                     deploy_processed.clone(),
                     sse_event.id,
@@ -338,6 +712,7 @@ async fn handle_single_event(
 
             match res {
                 Ok(_) => {
+                    metrics.record_saved(&source);
                     let _ = outbound_sse_data_sender.send(SseData::DeployProcessed {
                         deploy_hash,
                         account,
@@ -349,13 +724,17 @@ async fn handle_single_event(
                     }).await;
                 }
                 Err(DatabaseWriteError::UniqueConstraint(uc_err)) => {
+                    metrics.record_duplicate(&source);
                     debug!(
                         "Already received DeployProcessed ({}), logged in event_log",
                         HexFmt(deploy_hash.inner())
                     );
                     trace!(?uc_err);
                 }
-                Err(other_err) => warn!(?other_err, "Unexpected error saving DeployProcessed"),
+                Err(other_err) => {
+                    metrics.record_error(&source);
+                    warn!(?other_err, "Unexpected error saving DeployProcessed")
+                }
             }
         }
         SseData::Fault {
@@ -365,12 +744,13 @@ async fn handle_single_event(
         } => {
             let fault = Fault::new(era_id, public_key.clone(), timestamp);
             warn!(%fault, "Fault reported");
-            let res = sqlite_database
+            let res = database
                 .save_fault(fault.clone(), sse_event.id, sse_event.source.to_string())
                 .await;
 
             match res {
                 Ok(_) => {
+                    metrics.record_saved(&source);
                     let _ = outbound_sse_data_sender.send(SseData::Fault {
                         era_id,
                         timestamp,
@@ -378,10 +758,14 @@ async fn handle_single_event(
                     }).await;
                 }
                 Err(DatabaseWriteError::UniqueConstraint(uc_err)) => {
+                    metrics.record_duplicate(&source);
                     debug!("Already received Fault ({:#?}), logged in event_log", fault);
                     trace!(?uc_err);
                 }
-                Err(other_err) => warn!(?other_err, "Unexpected error saving Fault"),
+                Err(other_err) => {
+                    metrics.record_error(&source);
+                    warn!(?other_err, "Unexpected error saving Fault")
+                }
             }
         }
         SseData::FinalitySignature(fs) => {
@@ -389,7 +773,7 @@ async fn handle_single_event(
                 debug!("Finality Signature: {} for {}", fs.signature, fs.block_hash);
             }
             let finality_signature = FinalitySignature::new(fs.clone());
-            let res = sqlite_database
+            let res = database
                 .save_finality_signature(
                     finality_signature.clone(),
                     sse_event.id,
@@ -399,9 +783,11 @@ async fn handle_single_event(
 
             match res {
                 Ok(_) => {
+                    metrics.record_saved(&source);
                     let _ = outbound_sse_data_sender.send(SseData::FinalitySignature(fs)).await;
                 }
                 Err(DatabaseWriteError::UniqueConstraint(uc_err)) => {
+                    metrics.record_duplicate(&source);
                     debug!(
                         "Already received FinalitySignature ({}), logged in event_log",
                         fs.signature
@@ -409,6 +795,7 @@ async fn handle_single_event(
                     trace!(?uc_err);
                 }
                 Err(other_err) => {
+                    metrics.record_error(&source);
                     warn!(?other_err, "Unexpected error saving FinalitySignature")
                 }
             }
@@ -421,28 +808,34 @@ async fn handle_single_event(
             if enable_event_logging {
                 info!("Step at era: {}", era_id.value());
             }
-            let res = sqlite_database
+            let res = database
                 .save_step(step, sse_event.id, sse_event.source.to_string())
                 .await;
 
             match res {
                 Ok(_) => {
+                    metrics.record_saved(&source);
                     let _ = outbound_sse_data_sender.send(SseData::Step {
                         era_id,
                         execution_effect,
                     }).await;
                 }
                 Err(DatabaseWriteError::UniqueConstraint(uc_err)) => {
+                    metrics.record_duplicate(&source);
                     debug!(
                         "Already received Step ({}), logged in event_log",
                         era_id.value()
                     );
                     trace!(?uc_err);
                 }
-                Err(other_err) => warn!(?other_err, "Unexpected error saving Step"),
+                Err(other_err) => {
+                    metrics.record_error(&source);
+                    warn!(?other_err, "Unexpected error saving Step")
+                }
             }
         }
         SseData::Shutdown => {
+            metrics.node_disconnected(&source);
             warn!("Node ({}) is unavailable", sse_event.source.to_string());
         }
     }
@@ -453,8 +846,11 @@ async fn sse_processor(
     api_version_reporter: Sender<Result<ProtocolVersion, Error>>,
     mut inbound_sse_data_receiver: Receiver<SseEvent>,
     outbound_sse_data_sender: Sender<SseData>,
-    sqlite_database: SqliteDatabase,
+    database: Database,
     enable_event_logging: bool,
+    metrics: Metrics,
+    shutdown_token: CancellationToken,
+    disabled_event_types: Arc<HashSet<String>>,
 ) {
     // This task starts the listener pushing events to the sse_data_receiver
     tokio::spawn(async move {
@@ -462,13 +858,48 @@ async fn sse_processor(
             .stream_aggregated_events(api_version_reporter)
             .await;
     });
-    while let Some(sse_event) = inbound_sse_data_receiver.recv().await {
-        handle_single_event(
-            sse_event,
-            sqlite_database.clone(),
-            enable_event_logging,
-            outbound_sse_data_sender.clone(),
-        )
-        .await
+
+    loop {
+        tokio::select! {
+            biased;
+            _ = shutdown_token.cancelled() => {
+                // Stop pulling new inbound events, but drain whatever is
+                // already buffered so it isn't silently lost on shutdown.
+                inbound_sse_data_receiver.close();
+                while let Ok(sse_event) = inbound_sse_data_receiver.try_recv() {
+                    handle_single_event(
+                        sse_event,
+                        database.clone(),
+                        enable_event_logging,
+                        outbound_sse_data_sender.clone(),
+                        metrics.clone(),
+                        disabled_event_types.clone(),
+                    )
+                    .await
+                }
+                return;
+            }
+            maybe_sse_event = inbound_sse_data_receiver.recv() => {
+                let Some(sse_event) = maybe_sse_event else {
+                    return;
+                };
+                // `Sender::capacity()` is the number of *free* slots, not the
+                // queue depth - subtract it from the channel's total capacity
+                // to get how many messages are actually buffered.
+                metrics.set_outbound_channel_depth(
+                    (outbound_sse_data_sender.max_capacity() - outbound_sse_data_sender.capacity())
+                        as i64,
+                );
+                handle_single_event(
+                    sse_event,
+                    database.clone(),
+                    enable_event_logging,
+                    outbound_sse_data_sender.clone(),
+                    metrics.clone(),
+                    disabled_event_types.clone(),
+                )
+                .await
+            }
+        }
     }
 }
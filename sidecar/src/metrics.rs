@@ -0,0 +1,321 @@
+//! Lightweight Prometheus-style metrics registry for the sidecar.
+//!
+//! A single [`Metrics`] instance is constructed in `run` and cloned into every
+//! `sse_processor` task and the metrics server itself. The global counters
+//! and gauges are plain atomics behind an `Arc`, so recording a sample never
+//! requires an `await`; the per-source counters and the set of connected
+//! sources sit behind a `Mutex` that every record call locks briefly.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
+    sync::{
+        atomic::{AtomicI64, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use casper_event_types::SseData;
+use warp::Filter;
+
+const EVENT_VARIANTS: [&str; 8] = [
+    "BlockAdded",
+    "DeployAccepted",
+    "DeployProcessed",
+    "DeployExpired",
+    "Fault",
+    "FinalitySignature",
+    "Step",
+    "Shutdown",
+];
+
+#[derive(Default)]
+struct PerSourceCounters {
+    events_received: AtomicU64,
+    saved_total: AtomicU64,
+    duplicate_total: AtomicU64,
+    error_total: AtomicU64,
+}
+
+/// Process-wide telemetry shared between every `sse_processor` task and the
+/// `/metrics` endpoint.
+#[derive(Clone)]
+pub struct Metrics {
+    events_received_total: Arc<HashMap<&'static str, AtomicU64>>,
+    saved_total: Arc<AtomicU64>,
+    duplicate_total: Arc<AtomicU64>,
+    error_total: Arc<AtomicU64>,
+    per_source: Arc<Mutex<HashMap<String, Arc<PerSourceCounters>>>>,
+    connected_sources: Arc<Mutex<HashSet<String>>>,
+    outbound_channel_depth: Arc<AtomicI64>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let events_received_total = EVENT_VARIANTS
+            .iter()
+            .map(|variant| (*variant, AtomicU64::new(0)))
+            .collect();
+
+        Metrics {
+            events_received_total: Arc::new(events_received_total),
+            saved_total: Arc::new(AtomicU64::new(0)),
+            duplicate_total: Arc::new(AtomicU64::new(0)),
+            error_total: Arc::new(AtomicU64::new(0)),
+            per_source: Arc::new(Mutex::new(HashMap::new())),
+            connected_sources: Arc::new(Mutex::new(HashSet::new())),
+            outbound_channel_depth: Arc::new(AtomicI64::new(0)),
+        }
+    }
+
+    /// Records the receipt of a single event, keyed by its `SseData` variant
+    /// and the address of the node it arrived from.
+    pub fn record_event_received(&self, sse_data: &SseData, source: &str) {
+        if let Some(counter) = self
+            .events_received_total
+            .get(crate::event_type_name(sse_data))
+        {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+        self.per_source(source)
+            .events_received
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_saved(&self, source: &str) {
+        self.saved_total.fetch_add(1, Ordering::Relaxed);
+        self.per_source(source)
+            .saved_total
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_duplicate(&self, source: &str) {
+        self.duplicate_total.fetch_add(1, Ordering::Relaxed);
+        self.per_source(source)
+            .duplicate_total
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_error(&self, source: &str) {
+        self.error_total.fetch_add(1, Ordering::Relaxed);
+        self.per_source(source)
+            .error_total
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Marks `source` as live, e.g. once it reports its API version.
+    pub fn node_connected(&self, source: &str) {
+        self.connected_sources.lock().unwrap().insert(source.to_string());
+    }
+
+    /// Marks `source` as gone, e.g. once its `SseData::Shutdown` is handled.
+    pub fn node_disconnected(&self, source: &str) {
+        self.connected_sources.lock().unwrap().remove(source);
+    }
+
+    pub fn set_outbound_channel_depth(&self, depth: i64) {
+        self.outbound_channel_depth.store(depth, Ordering::Relaxed);
+    }
+
+    pub fn saved_total(&self) -> u64 {
+        self.saved_total.load(Ordering::Relaxed)
+    }
+
+    pub fn duplicate_total(&self) -> u64 {
+        self.duplicate_total.load(Ordering::Relaxed)
+    }
+
+    pub fn error_total(&self) -> u64 {
+        self.error_total.load(Ordering::Relaxed)
+    }
+
+    fn per_source(&self, source: &str) -> Arc<PerSourceCounters> {
+        let mut guard = self.per_source.lock().unwrap();
+        guard
+            .entry(source.to_string())
+            .or_insert_with(|| Arc::new(PerSourceCounters::default()))
+            .clone()
+    }
+
+    /// Renders the current snapshot in Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_events_received_total Total number of SSE events received, by event type.\n\
+             # TYPE sidecar_events_received_total counter"
+        );
+        for variant in EVENT_VARIANTS {
+            let value = self.events_received_total[variant].load(Ordering::Relaxed);
+            let _ = writeln!(
+                out,
+                "sidecar_events_received_total{{event_type=\"{variant}\"}} {value}"
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_db_saved_total Total number of events persisted to the database.\n\
+             # TYPE sidecar_db_saved_total counter\n\
+             sidecar_db_saved_total {}",
+            self.saved_total.load(Ordering::Relaxed)
+        );
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_db_duplicate_total Total number of events rejected as duplicates.\n\
+             # TYPE sidecar_db_duplicate_total counter\n\
+             sidecar_db_duplicate_total {}",
+            self.duplicate_total.load(Ordering::Relaxed)
+        );
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_db_error_total Total number of unexpected database write errors.\n\
+             # TYPE sidecar_db_error_total counter\n\
+             sidecar_db_error_total {}",
+            self.error_total.load(Ordering::Relaxed)
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_source_events_received_total Events received, by upstream node.\n\
+             # TYPE sidecar_source_events_received_total counter"
+        );
+        for (source, counters) in self.per_source.lock().unwrap().iter() {
+            let _ = writeln!(
+                out,
+                "sidecar_source_events_received_total{{source=\"{source}\"}} {}",
+                counters.events_received.load(Ordering::Relaxed)
+            );
+        }
+
+        // Per-source breakdown of the same three outcomes already reported
+        // globally above, so an operator can tell which node is lagging or
+        // producing errors rather than just that one is, somewhere.
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_source_saved_total Events persisted to the database, by upstream node.\n\
+             # TYPE sidecar_source_saved_total counter"
+        );
+        for (source, counters) in self.per_source.lock().unwrap().iter() {
+            let _ = writeln!(
+                out,
+                "sidecar_source_saved_total{{source=\"{source}\"}} {}",
+                counters.saved_total.load(Ordering::Relaxed)
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_source_duplicate_total Events rejected as duplicates, by upstream node.\n\
+             # TYPE sidecar_source_duplicate_total counter"
+        );
+        for (source, counters) in self.per_source.lock().unwrap().iter() {
+            let _ = writeln!(
+                out,
+                "sidecar_source_duplicate_total{{source=\"{source}\"}} {}",
+                counters.duplicate_total.load(Ordering::Relaxed)
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_source_error_total Unexpected database write errors, by upstream node.\n\
+             # TYPE sidecar_source_error_total counter"
+        );
+        for (source, counters) in self.per_source.lock().unwrap().iter() {
+            let _ = writeln!(
+                out,
+                "sidecar_source_error_total{{source=\"{source}\"}} {}",
+                counters.error_total.load(Ordering::Relaxed)
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_connected_nodes Number of currently connected upstream nodes.\n\
+             # TYPE sidecar_connected_nodes gauge\n\
+             sidecar_connected_nodes {}",
+            self.connected_sources.lock().unwrap().len()
+        );
+        let _ = writeln!(
+            out,
+            "# HELP sidecar_outbound_channel_depth Current depth of the outbound broadcast channel.\n\
+             # TYPE sidecar_outbound_channel_depth gauge\n\
+             sidecar_outbound_channel_depth {}",
+            self.outbound_channel_depth.load(Ordering::Relaxed)
+        );
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_reports_zeroed_counters_and_gauges_before_any_events() {
+        let metrics = Metrics::new();
+        let rendered = metrics.render();
+
+        assert!(rendered.contains("sidecar_db_saved_total 0"));
+        assert!(rendered.contains("sidecar_db_duplicate_total 0"));
+        assert!(rendered.contains("sidecar_db_error_total 0"));
+        assert!(rendered.contains("sidecar_connected_nodes 0"));
+        assert!(rendered.contains("sidecar_outbound_channel_depth 0"));
+    }
+
+    #[test]
+    fn render_breaks_down_saved_duplicate_and_error_totals_by_source() {
+        let metrics = Metrics::new();
+        metrics.record_saved("node-a");
+        metrics.record_saved("node-a");
+        metrics.record_duplicate("node-b");
+        metrics.record_error("node-b");
+
+        let rendered = metrics.render();
+
+        assert!(rendered.contains("sidecar_db_saved_total 2"));
+        assert!(rendered.contains("sidecar_source_saved_total{source=\"node-a\"} 2"));
+        assert!(rendered.contains("sidecar_source_duplicate_total{source=\"node-b\"} 1"));
+        assert!(rendered.contains("sidecar_source_error_total{source=\"node-b\"} 1"));
+    }
+
+    #[test]
+    fn connected_nodes_gauge_tracks_connect_and_disconnect() {
+        let metrics = Metrics::new();
+        metrics.node_connected("node-a");
+        metrics.node_connected("node-b");
+        assert!(metrics.render().contains("sidecar_connected_nodes 2"));
+
+        metrics.node_disconnected("node-a");
+        assert!(metrics.render().contains("sidecar_connected_nodes 1"));
+
+        // Disconnecting a node that was never connected (or already
+        // disconnected) is a no-op rather than underflowing.
+        metrics.node_disconnected("node-a");
+        assert!(metrics.render().contains("sidecar_connected_nodes 1"));
+    }
+
+    #[test]
+    fn outbound_channel_depth_gauge_reflects_last_recorded_value() {
+        let metrics = Metrics::new();
+        metrics.set_outbound_channel_depth(42);
+        assert!(metrics.render().contains("sidecar_outbound_channel_depth 42"));
+    }
+}
+
+/// Builds and serves the `GET /metrics` route, analogous to how
+/// `rest_server::run_server` serves `GET /health`.
+pub async fn run_server(port: u16, metrics: Metrics) {
+    let route = warp::path("metrics").and(warp::get()).map(move || {
+        warp::reply::with_header(
+            metrics.render(),
+            "content-type",
+            "text/plain; version=0.0.4",
+        )
+    });
+
+    warp::serve(route).run(([0, 0, 0, 0], port)).await;
+}
@@ -0,0 +1,231 @@
+//! A `DatabaseWriter` implementation backed by PostgreSQL, used when
+//! `storage.engine` in the config is set to `postgres` instead of `sqlite`.
+//!
+//! The statement text lives in `sql::postgres` (the Postgres dialect
+//! counterpart of `sql::sqlite`); conflicts on a previously-seen event map to
+//! the same `DatabaseWriteError::UniqueConstraint` arm that
+//! `SqliteDatabase` returns, so callers in `main.rs` don't need to know
+//! which backend they're talking to.
+
+use async_trait::async_trait;
+use deadpool_postgres::{Config as PoolConfig, Pool, Runtime};
+use tokio_postgres::NoTls;
+
+use crate::{
+    sql::postgres as sql,
+    types::{
+        config::PostgresConfig,
+        database::{DatabaseWriteError, DatabaseWriter},
+        sse_events::{
+            BlockAdded, DeployAccepted, DeployExpired, DeployProcessed, Fault, FinalitySignature,
+            Step,
+        },
+    },
+};
+
+/// Connection-pooled PostgreSQL backend for the sidecar's event store.
+#[derive(Clone)]
+pub struct PostgresDatabase {
+    pool: Pool,
+}
+
+impl PostgresDatabase {
+    /// Connects to the configured database and runs the same set of
+    /// migrations `SqliteDatabase::new` applies to a fresh SQLite file.
+    pub async fn new(postgres_config: PostgresConfig) -> Result<Self, DatabaseWriteError> {
+        let mut pool_config = PoolConfig::new();
+        pool_config.host = Some(postgres_config.host);
+        pool_config.port = Some(postgres_config.port);
+        pool_config.user = Some(postgres_config.user);
+        pool_config.password = Some(postgres_config.password);
+        pool_config.dbname = Some(postgres_config.database);
+
+        let pool = pool_config
+            .create_pool(Some(Runtime::Tokio1), NoTls)
+            .map_err(|err| DatabaseWriteError::Connection(err.to_string()))?;
+
+        let database = PostgresDatabase { pool };
+        database.run_migrations().await?;
+        Ok(database)
+    }
+
+    async fn run_migrations(&self) -> Result<(), DatabaseWriteError> {
+        let client = self
+            .pool
+            .get()
+            .await
+            .map_err(|err| DatabaseWriteError::Connection(err.to_string()))?;
+        for statement in sql::CREATE_TABLE_STMTS {
+            client
+                .batch_execute(statement)
+                .await
+                .map_err(|err| DatabaseWriteError::Connection(err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Every statement in `sql::postgres` ends in `ON CONFLICT ... DO
+    /// NOTHING`, so a duplicate never raises a unique-violation error for us
+    /// to catch - it just inserts zero rows. Treat that outcome as the same
+    /// `UniqueConstraint` case `SqliteDatabase` reports for a duplicate.
+    async fn execute(
+        &self,
+        statement: &str,
+        params: &[&(dyn tokio_postgres::types::ToSql + Sync)],
+    ) -> Result<(), DatabaseWriteError> {
+        let client = self
+            .pool
+            .get()
+            .await
+            .map_err(|err| DatabaseWriteError::Connection(err.to_string()))?;
+
+        let rows_affected = client
+            .execute(statement, params)
+            .await
+            .map_err(|err| DatabaseWriteError::Connection(err.to_string()))?;
+
+        if rows_affected == 0 {
+            Err(DatabaseWriteError::UniqueConstraint(
+                "row already present".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[async_trait]
+impl DatabaseWriter for PostgresDatabase {
+    async fn save_block_added(
+        &self,
+        block_added: BlockAdded,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        // `tokio_postgres` only implements `ToSql` for `u32` against the
+        // Postgres `OID` type, not the `BIGINT` column `sql::postgres`
+        // declares, so every id is cast to `i64` before binding.
+        let event_id = event_id as i64;
+        self.execute(
+            sql::INSERT_BLOCK_ADDED,
+            &[
+                &block_added.block_hash.to_string(),
+                &serde_json::to_string(&block_added.block).unwrap_or_default(),
+                &event_id,
+                &event_source_address,
+            ],
+        )
+        .await
+    }
+
+    async fn save_deploy_accepted(
+        &self,
+        deploy_accepted: DeployAccepted,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        let event_id = event_id as i64;
+        self.execute(
+            sql::INSERT_DEPLOY_ACCEPTED,
+            &[
+                &deploy_accepted.deploy_hash().to_string(),
+                &serde_json::to_string(&deploy_accepted.deploy).unwrap_or_default(),
+                &event_id,
+                &event_source_address,
+            ],
+        )
+        .await
+    }
+
+    async fn save_deploy_processed(
+        &self,
+        deploy_processed: DeployProcessed,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        let event_id = event_id as i64;
+        self.execute(
+            sql::INSERT_DEPLOY_PROCESSED,
+            &[
+                &deploy_processed.deploy_hash.to_string(),
+                &serde_json::to_string(&deploy_processed).unwrap_or_default(),
+                &event_id,
+                &event_source_address,
+            ],
+        )
+        .await
+    }
+
+    async fn save_deploy_expired(
+        &self,
+        deploy_expired: DeployExpired,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        let event_id = event_id as i64;
+        self.execute(
+            sql::INSERT_DEPLOY_EXPIRED,
+            &[
+                &deploy_expired.deploy_hash.to_string(),
+                &event_id,
+                &event_source_address,
+            ],
+        )
+        .await
+    }
+
+    async fn save_fault(
+        &self,
+        fault: Fault,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        let event_id = event_id as i64;
+        self.execute(
+            sql::INSERT_FAULT,
+            &[
+                &fault.era_id.value().to_string(),
+                &fault.public_key.to_string(),
+                &event_id,
+                &event_source_address,
+            ],
+        )
+        .await
+    }
+
+    async fn save_finality_signature(
+        &self,
+        finality_signature: FinalitySignature,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        let event_id = event_id as i64;
+        self.execute(
+            sql::INSERT_FINALITY_SIGNATURE,
+            &[
+                &finality_signature.signature.to_string(),
+                &event_id,
+                &event_source_address,
+            ],
+        )
+        .await
+    }
+
+    async fn save_step(
+        &self,
+        step: Step,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError> {
+        let event_id = event_id as i64;
+        self.execute(
+            sql::INSERT_STEP,
+            &[
+                &step.era_id.value().to_string(),
+                &event_id,
+                &event_source_address,
+            ],
+        )
+        .await
+    }
+}
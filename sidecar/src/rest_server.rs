@@ -0,0 +1,43 @@
+//! A minimal REST endpoint served alongside the SSE event stream, currently
+//! just a `/health` check for readiness probes; querying stored events isn't
+//! implemented yet, so no `DatabaseWriter` is threaded through here.
+//!
+//! Like `event_stream_server`, this binds no socket of its own: `run` in
+//! `main.rs` reserves the port up front via `bind_port` and hands the
+//! resulting listener in here, so a port conflict is reported before any
+//! task is spawned. Shutdown is likewise driven externally, via
+//! `shutdown_token` rather than this module installing its own signal
+//! handler.
+
+use anyhow::{Context, Error};
+use tokio_util::sync::CancellationToken;
+use warp::Filter;
+
+use crate::types::config::RestServerConfig;
+
+/// Serves the REST API on `listener` until `shutdown_token` is cancelled,
+/// at which point in-flight requests are allowed to finish before the
+/// listener is closed.
+pub async fn run_server(
+    config: RestServerConfig,
+    shutdown_token: CancellationToken,
+    listener: std::net::TcpListener,
+) -> Result<(), Error> {
+    tracing::info!(port = config.port, "Starting REST server");
+
+    let health_route = warp::path("health")
+        .and(warp::get())
+        .map(|| warp::reply::json(&serde_json::json!({ "status": "ok" })));
+
+    let listener = tokio::net::TcpListener::from_std(listener)
+        .context("Error handing bound listener to the REST server")?;
+    let incoming = tokio_stream::wrappers::TcpListenerStream::new(listener);
+
+    warp::serve(health_route)
+        .serve_incoming_with_graceful_shutdown(incoming, async move {
+            shutdown_token.cancelled().await;
+        })
+        .await;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! Postgres-dialect statements for the [`PostgresDatabase`](crate::postgres_database::PostgresDatabase)
+//! backend. Every insert ends in `ON CONFLICT ... DO NOTHING` rather than
+//! raising on the unique constraint, so a re-delivered event is a silent
+//! no-op at the SQL level - `PostgresDatabase::execute` turns the resulting
+//! zero-rows-affected outcome into the same `DatabaseWriteError::UniqueConstraint`
+//! `SqliteDatabase` returns for a duplicate.
+
+pub const CREATE_TABLE_STMTS: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS block_added (
+        block_hash TEXT NOT NULL,
+        raw TEXT NOT NULL,
+        event_id BIGINT NOT NULL,
+        event_source_address TEXT NOT NULL,
+        UNIQUE (block_hash)
+    )",
+    "CREATE TABLE IF NOT EXISTS deploy_accepted (
+        deploy_hash TEXT NOT NULL,
+        raw TEXT NOT NULL,
+        event_id BIGINT NOT NULL,
+        event_source_address TEXT NOT NULL,
+        UNIQUE (deploy_hash)
+    )",
+    "CREATE TABLE IF NOT EXISTS deploy_processed (
+        deploy_hash TEXT NOT NULL,
+        raw TEXT NOT NULL,
+        event_id BIGINT NOT NULL,
+        event_source_address TEXT NOT NULL,
+        UNIQUE (deploy_hash)
+    )",
+    "CREATE TABLE IF NOT EXISTS deploy_expired (
+        deploy_hash TEXT NOT NULL,
+        event_id BIGINT NOT NULL,
+        event_source_address TEXT NOT NULL,
+        UNIQUE (deploy_hash)
+    )",
+    "CREATE TABLE IF NOT EXISTS fault (
+        era_id TEXT NOT NULL,
+        public_key TEXT NOT NULL,
+        event_id BIGINT NOT NULL,
+        event_source_address TEXT NOT NULL,
+        UNIQUE (era_id, public_key)
+    )",
+    "CREATE TABLE IF NOT EXISTS finality_signature (
+        signature TEXT NOT NULL,
+        event_id BIGINT NOT NULL,
+        event_source_address TEXT NOT NULL,
+        UNIQUE (signature)
+    )",
+    "CREATE TABLE IF NOT EXISTS step (
+        era_id TEXT NOT NULL,
+        event_id BIGINT NOT NULL,
+        event_source_address TEXT NOT NULL,
+        UNIQUE (era_id)
+    )",
+];
+
+pub const INSERT_BLOCK_ADDED: &str = "INSERT INTO block_added (block_hash, raw, event_id, event_source_address) \
+     VALUES ($1, $2, $3, $4) ON CONFLICT (block_hash) DO NOTHING";
+
+pub const INSERT_DEPLOY_ACCEPTED: &str = "INSERT INTO deploy_accepted (deploy_hash, raw, event_id, event_source_address) \
+     VALUES ($1, $2, $3, $4) ON CONFLICT (deploy_hash) DO NOTHING";
+
+pub const INSERT_DEPLOY_PROCESSED: &str = "INSERT INTO deploy_processed (deploy_hash, raw, event_id, event_source_address) \
+     VALUES ($1, $2, $3, $4) ON CONFLICT (deploy_hash) DO NOTHING";
+
+pub const INSERT_DEPLOY_EXPIRED: &str = "INSERT INTO deploy_expired (deploy_hash, event_id, event_source_address) \
+     VALUES ($1, $2, $3) ON CONFLICT (deploy_hash) DO NOTHING";
+
+pub const INSERT_FAULT: &str = "INSERT INTO fault (era_id, public_key, event_id, event_source_address) \
+     VALUES ($1, $2, $3, $4) ON CONFLICT (era_id, public_key) DO NOTHING";
+
+pub const INSERT_FINALITY_SIGNATURE: &str = "INSERT INTO finality_signature (signature, event_id, event_source_address) \
+     VALUES ($1, $2, $3) ON CONFLICT (signature) DO NOTHING";
+
+pub const INSERT_STEP: &str = "INSERT INTO step (era_id, event_id, event_source_address) \
+     VALUES ($1, $2, $3) ON CONFLICT (era_id) DO NOTHING";
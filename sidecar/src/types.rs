@@ -0,0 +1,3 @@
+pub mod config;
+pub mod database;
+pub mod sse_events;
@@ -0,0 +1,109 @@
+//! The sidecar's TOML configuration schema, and the loader that parses it.
+
+use std::{fs, path::Path};
+
+use anyhow::{Context, Error};
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Config {
+    pub connections: Vec<Connection>,
+    pub inbound_channel_size: Option<usize>,
+    pub outbound_channel_size: Option<usize>,
+    pub storage: StorageConfig,
+    pub rest_server: RestServerConfig,
+    pub event_stream_server: EventStreamServerConfig,
+    #[serde(default)]
+    pub metrics_server: MetricsServerConfig,
+}
+
+/// One upstream node the sidecar connects to.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Connection {
+    pub ip_address: String,
+    pub sse_port: u16,
+    pub rest_port: u16,
+    pub max_retries: u8,
+    pub delay_between_retries_in_seconds: u16,
+    pub allow_partial_connection: bool,
+    pub connection_timeout_in_seconds: Option<u16>,
+    #[serde(default)]
+    pub enable_logging: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StorageConfig {
+    pub storage_path: String,
+    #[serde(default)]
+    pub sqlite_config: SqliteConfig,
+    /// Which `DatabaseWriter` backend `run`/`run_import_command` instantiate.
+    #[serde(default)]
+    pub engine: StorageEngine,
+    /// Only read when `engine` is [`StorageEngine::Postgres`].
+    #[serde(default)]
+    pub postgres_config: PostgresConfig,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SqliteConfig {
+    #[serde(default)]
+    pub max_connections: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageEngine {
+    #[default]
+    Sqlite,
+    Postgres,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct PostgresConfig {
+    pub host: String,
+    pub port: u16,
+    pub user: String,
+    pub password: String,
+    pub database: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RestServerConfig {
+    pub port: u16,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventStreamServerConfig {
+    pub port: u16,
+    pub event_stream_buffer_length: usize,
+    pub max_concurrent_subscribers: usize,
+    /// Event type names (see `event_type_name` in `main.rs`) that
+    /// `handle_single_event` should drop instead of persisting/re-broadcasting.
+    #[serde(default)]
+    pub disabled_event_types: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MetricsServerConfig {
+    #[serde(default = "default_metrics_port")]
+    pub port: u16,
+}
+
+impl Default for MetricsServerConfig {
+    fn default() -> Self {
+        MetricsServerConfig {
+            port: default_metrics_port(),
+        }
+    }
+}
+
+fn default_metrics_port() -> u16 {
+    8888
+}
+
+/// Reads and parses the TOML config file at `path`.
+pub fn read_config(path: impl AsRef<Path>) -> Result<Config, Error> {
+    let contents = fs::read_to_string(path.as_ref())
+        .with_context(|| format!("Error reading config file {}", path.as_ref().display()))?;
+    toml::from_str(&contents).context("Error parsing config file")
+}
@@ -0,0 +1,92 @@
+//! The storage-backend-agnostic surface every `Database` variant implements.
+//!
+//! `SqliteDatabase` and `PostgresDatabase` both implement [`DatabaseWriter`]
+//! directly; `main.rs`'s `Database` enum then implements it a third time by
+//! dispatching to whichever variant is active, so callers never match on the
+//! backend themselves.
+
+use std::fmt;
+
+use async_trait::async_trait;
+
+use crate::types::sse_events::{
+    BlockAdded, DeployAccepted, DeployExpired, DeployProcessed, Fault, FinalitySignature, Step,
+};
+
+/// Error surfaced by a `DatabaseWriter::save_*` call.
+#[derive(Debug)]
+pub enum DatabaseWriteError {
+    /// The event had already been recorded (same dedup key as a prior save).
+    UniqueConstraint(String),
+    /// The backend couldn't be reached, or the write itself failed for a
+    /// reason unrelated to deduplication.
+    Connection(String),
+}
+
+impl fmt::Display for DatabaseWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatabaseWriteError::UniqueConstraint(err) => {
+                write!(f, "unique constraint violation: {err}")
+            }
+            DatabaseWriteError::Connection(err) => write!(f, "database error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for DatabaseWriteError {}
+
+/// Persists each `SseData` variant, keyed by its own dedup key, returning
+/// [`DatabaseWriteError::UniqueConstraint`] when `event_id`/the row's natural
+/// key has already been saved rather than treating it as a hard failure.
+#[async_trait]
+pub trait DatabaseWriter: Send + Sync {
+    async fn save_block_added(
+        &self,
+        block_added: BlockAdded,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError>;
+
+    async fn save_deploy_accepted(
+        &self,
+        deploy_accepted: DeployAccepted,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError>;
+
+    async fn save_deploy_processed(
+        &self,
+        deploy_processed: DeployProcessed,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError>;
+
+    async fn save_deploy_expired(
+        &self,
+        deploy_expired: DeployExpired,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError>;
+
+    async fn save_fault(
+        &self,
+        fault: Fault,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError>;
+
+    async fn save_finality_signature(
+        &self,
+        finality_signature: FinalitySignature,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError>;
+
+    async fn save_step(
+        &self,
+        step: Step,
+        event_id: u32,
+        event_source_address: String,
+    ) -> Result<(), DatabaseWriteError>;
+}
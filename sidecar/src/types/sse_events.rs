@@ -0,0 +1,147 @@
+//! Thin wrappers around the raw `SseData` payloads that `DatabaseWriter`
+//! implementations persist. Each wrapper owns exactly the fields its own
+//! dedup key and storage row need - the full `SseData` variant stays in
+//! `handle_single_event` for re-broadcasting once the write succeeds.
+
+use std::ops::Deref;
+
+use casper_types::{
+    Block, BlockHash, Deploy, DeployHash, EraId, ExecutionEffect, ExecutionResult,
+    FinalitySignature as RawFinalitySignature, PublicKey, TimeDiff, Timestamp,
+};
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct BlockAdded {
+    pub block_hash: BlockHash,
+    pub block: Block,
+}
+
+impl BlockAdded {
+    pub fn new(block_hash: BlockHash, block: Block) -> Self {
+        BlockAdded { block_hash, block }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DeployAccepted {
+    pub deploy: Deploy,
+}
+
+impl DeployAccepted {
+    pub fn new(deploy: Deploy) -> Self {
+        DeployAccepted { deploy }
+    }
+
+    pub fn deploy_hash(&self) -> DeployHash {
+        *self.deploy.id()
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DeployProcessed {
+    pub deploy_hash: DeployHash,
+    pub account: PublicKey,
+    pub timestamp: Timestamp,
+    pub ttl: TimeDiff,
+    pub dependencies: Vec<DeployHash>,
+    pub block_hash: BlockHash,
+    pub execution_result: ExecutionResult,
+}
+
+impl DeployProcessed {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        deploy_hash: DeployHash,
+        account: PublicKey,
+        timestamp: Timestamp,
+        ttl: TimeDiff,
+        dependencies: Vec<DeployHash>,
+        block_hash: BlockHash,
+        execution_result: ExecutionResult,
+    ) -> Self {
+        DeployProcessed {
+            deploy_hash,
+            account,
+            timestamp,
+            ttl,
+            dependencies,
+            block_hash,
+            execution_result,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeployExpired {
+    pub deploy_hash: DeployHash,
+}
+
+impl DeployExpired {
+    pub fn new(deploy_hash: DeployHash) -> Self {
+        DeployExpired { deploy_hash }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Fault {
+    pub era_id: EraId,
+    pub public_key: PublicKey,
+    pub timestamp: Timestamp,
+}
+
+impl Fault {
+    pub fn new(era_id: EraId, public_key: PublicKey, timestamp: Timestamp) -> Self {
+        Fault {
+            era_id,
+            public_key,
+            timestamp,
+        }
+    }
+}
+
+impl std::fmt::Display for Fault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "era {} reported by {}",
+            self.era_id.value(),
+            self.public_key
+        )
+    }
+}
+
+/// Wraps the raw node-reported finality signature; `Deref`s to it so callers
+/// can still reach `.signature`/`.block_hash` without this module having to
+/// re-declare every field.
+#[derive(Clone, Debug)]
+pub struct FinalitySignature(RawFinalitySignature);
+
+impl FinalitySignature {
+    pub fn new(finality_signature: RawFinalitySignature) -> Self {
+        FinalitySignature(finality_signature)
+    }
+}
+
+impl Deref for FinalitySignature {
+    type Target = RawFinalitySignature;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Step {
+    pub era_id: EraId,
+    pub execution_effect: ExecutionEffect,
+}
+
+impl Step {
+    pub fn new(era_id: EraId, execution_effect: ExecutionEffect) -> Self {
+        Step {
+            era_id,
+            execution_effect,
+        }
+    }
+}